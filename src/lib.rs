@@ -1,19 +1,37 @@
 //! SPSC Ringbuffer.
-
-use atomic_enum::atomic_enum;
-#[cfg(feature = "serialization")]
-use serde::{
-    Deserialize,
-    Serialize,
+//!
+//! `no_std` unless the `std` feature (on by default in `Cargo.toml`) is
+//! enabled. The heap-allocated, runtime-sized
+//! [`SpscRingbuffer`] and its [`Producer`]/[`Consumer`] split need an
+//! allocator, so they additionally require the `alloc` feature (implied by
+//! `std`); the compile-time-sized [`fixed::SpscRingbuffer`] behind the
+//! `const-generic` feature needs neither.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::sync::atomic::{
+    AtomicUsize,
+    Ordering,
 };
-use std::{
+
+#[cfg(feature = "alloc")]
+use core::{
     cell::UnsafeCell,
-    sync::atomic::{
-        AtomicUsize,
-        Ordering,
-    },
+    fmt,
+    marker::PhantomData,
+    mem::MaybeUninit,
+    ptr,
 };
 
+#[cfg(feature = "alloc")]
+use alloc::{
+    sync::Arc,
+    vec::Vec,
+};
+
+#[cfg(feature = "alloc")]
 #[derive(Debug)]
 struct UnsafeVec<T>(UnsafeCell<Vec<T>>);
 
@@ -27,133 +45,464 @@ pub enum StoreErrorKind {
     Full,
 }
 
-#[atomic_enum]
-#[derive(PartialEq)]
-#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
-enum LimitKind {
-    Empty,
-    Full,
-}
-
-#[derive(Debug)]
-#[cfg_attr(feature = "serialization", derive(Serialize, Deserialize))]
-pub struct SpscRingbuffer<T: Copy + Default> {
-    buffer: UnsafeVec<T>,
+/// The pair of atomic read/write indices that every backing-store variant
+/// advances around the reserved-slot scheme (one of `capacity` slots is
+/// always kept empty to disambiguate full from empty).
+///
+/// Both [`Inner`] (heap-allocated, sized at runtime) and
+/// [`fixed::SpscRingbuffer`] (inline array, sized at compile time) wrap a
+/// `capacity`-slot ring with this exact arithmetic; factoring it out here
+/// means an ordering fix only has to be made once instead of being
+/// re-derived and re-audited for every backing-store type.
+#[derive(Debug, Default)]
+struct Cursors {
     write_index: AtomicUsize,
     read_index: AtomicUsize,
-    limit_kind: AtomicLimitKind,
-    size: usize,
 }
 
-impl<T: Copy + Default> SpscRingbuffer<T> {
-    pub fn new(size: usize) -> SpscRingbuffer<T> {
-        SpscRingbuffer {
-            buffer: UnsafeVec(UnsafeCell::new(vec![T::default(); size])),
+impl Cursors {
+    const fn new() -> Cursors {
+        Cursors {
             write_index: AtomicUsize::new(0),
             read_index: AtomicUsize::new(0),
-            limit_kind: AtomicLimitKind::new(LimitKind::Empty),
+        }
+    }
+
+    fn load_read(&self, order: Ordering) -> usize {
+        self.read_index.load(order)
+    }
+
+    fn load_write(&self, order: Ordering) -> usize {
+        self.write_index.load(order)
+    }
+
+    fn store_read(&self, value: usize, order: Ordering) {
+        self.read_index.store(value, order);
+    }
+
+    fn store_write(&self, value: usize, order: Ordering) {
+        self.write_index.store(value, order);
+    }
+
+    fn read_available(&self, capacity: usize) -> usize {
+        let write_index = self.load_write(Ordering::Acquire);
+        let read_index = self.load_read(Ordering::Acquire);
+
+        (write_index + capacity - read_index) % capacity
+    }
+
+    fn write_available(&self, capacity: usize) -> usize {
+        (capacity - 1) - self.read_available(capacity)
+    }
+
+    /// Advances the read cursor from `from` by `by` slots, wrapping at
+    /// `capacity`. Shared by the scalar and bulk-slice pop paths so the
+    /// wraparound computation can't drift between them.
+    fn advance_read(&self, from: usize, by: usize, capacity: usize) {
+        self.store_read((from + by) % capacity, Ordering::Release);
+    }
+
+    /// Advances the write cursor from `from` by `by` slots, wrapping at
+    /// `capacity`. Shared by the scalar and bulk-slice push paths so the
+    /// wraparound computation can't drift between them.
+    fn advance_write(&self, from: usize, by: usize, capacity: usize) {
+        self.store_write((from + by) % capacity, Ordering::Release);
+    }
+}
+
+/// The data shared between a [`Producer`] and [`Consumer`] pair, reachable
+/// only through them (or through [`SpscRingbuffer`] before it is split).
+///
+/// This type is deliberately private: it is the only thing that is ever
+/// wrapped in an `Arc` and handed to more than one thread, and it is the only
+/// thing marked `Sync`. Keeping it out of the public API is what makes the
+/// "exactly one producer, one consumer" invariant a compile-time guarantee
+/// rather than a convention — there is no public way to obtain a second
+/// shared handle to it other than through `SpscRingbuffer::split`.
+#[cfg(feature = "alloc")]
+struct Inner<T> {
+    buffer: UnsafeVec<MaybeUninit<T>>,
+    cursors: Cursors,
+    size: usize,
+}
+
+/// Hand-rolled instead of derived: deriving would add a `T: Debug` bound
+/// that nothing here actually needs, since no slot's contents are ever
+/// printed — only the capacity and how much of it is occupied.
+#[cfg(feature = "alloc")]
+impl<T> fmt::Debug for Inner<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Inner")
+            .field("capacity", &self.size)
+            .field("read_available", &self.read_available())
+            .finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Inner<T> {
+    fn new(size: usize) -> Inner<T> {
+        assert!(size > 0, "SpscRingbuffer needs at least 1 slot of usable capacity");
+
+        let slots = (0..size + 1).map(|_| MaybeUninit::uninit()).collect();
+
+        Inner {
+            buffer: UnsafeVec(UnsafeCell::new(slots)),
+            cursors: Cursors::new(),
             size,
         }
     }
 
-    pub fn is_empty(&self) -> bool {
+    fn is_empty(&self) -> bool {
         self.read_available() == 0
     }
 
-    pub fn is_full(&self) -> bool {
+    fn is_full(&self) -> bool {
         self.write_available() == 0
     }
 
-    pub fn clear(&self) {
-        self.write_index.store(0, Ordering::Relaxed);
-        self.read_index.store(0, Ordering::Relaxed);
-        self.limit_kind.store(LimitKind::Empty, Ordering::Relaxed);
+    /// Drops every currently occupied slot and resets the buffer to empty.
+    fn clear(&self) {
+        while self.pop().is_ok() {
+        }
     }
 
-    pub fn read_available(&self) -> usize {
-        let write_index = self.write_index.load(Ordering::Acquire);
-        let read_index = self.read_index.load(Ordering::Acquire);
+    fn read_available(&self) -> usize {
+        self.cursors.read_available(self.size + 1)
+    }
 
-        if write_index == read_index {
-            match self.limit_kind.load(Ordering::Relaxed) {
-                LimitKind::Empty => 0,
-                LimitKind::Full => self.size,
-            }
-        } else if write_index > read_index {
-            write_index - read_index
-        } else {
-            (self.size - read_index) + write_index
+    fn write_available(&self) -> usize {
+        self.cursors.write_available(self.size + 1)
+    }
+
+    fn pop(&self) -> Result<T, LoadErrorKind> {
+        if self.is_empty() {
+            return Err(LoadErrorKind::Empty);
         }
+
+        let read_index = self.cursors.load_read(Ordering::Relaxed);
+
+        let item = unsafe {
+            let slot = self.buffer.0.get().as_ref().unwrap().get_unchecked(read_index);
+            ptr::read(slot.as_ptr())
+        };
+
+        self.cursors.advance_read(read_index, 1, self.size + 1);
+
+        Ok(item)
     }
 
-    pub fn write_available(&self) -> usize {
-        let write_index = self.write_index.load(Ordering::Acquire);
-        let read_index = self.read_index.load(Ordering::Acquire);
+    fn push(&self, item: T) -> Result<(), StoreErrorKind> {
+        if self.is_full() {
+            return Err(StoreErrorKind::Full);
+        }
 
-        if write_index == read_index {
-            match self.limit_kind.load(Ordering::Relaxed) {
-                LimitKind::Empty => self.size,
-                LimitKind::Full => 0,
-            }
-        } else if write_index < read_index {
-            read_index - write_index
-        } else {
-            (self.size - write_index) + read_index
+        let write_index = self.cursors.load_write(Ordering::Relaxed);
+
+        unsafe {
+            let slot = self.buffer.0.get().as_mut().unwrap().get_unchecked_mut(write_index);
+            ptr::write(slot.as_mut_ptr(), item);
         }
+
+        self.cursors.advance_write(write_index, 1, self.size + 1);
+
+        Ok(())
     }
+}
 
-    pub fn pop(&self) -> Result<T, LoadErrorKind> {
-        if self.is_empty() {
-            return Err(LoadErrorKind::Empty);
+#[cfg(feature = "alloc")]
+impl<T: Copy> Inner<T> {
+    /// Pushes as many elements of `src` as fit, returning the count copied.
+    ///
+    /// At most two `memcpy`s are issued (one on each side of the write
+    /// cursor's wraparound point), instead of looping element-by-element.
+    /// Restricted to `T: Copy`, since a shared `&[T]` source cannot be moved
+    /// from without duplicating ownership.
+    fn push_slice(&self, src: &[T]) -> usize {
+        let capacity = self.size + 1;
+        let to_write = src.len().min(self.write_available());
+
+        if to_write == 0 {
+            return 0;
         }
 
-        let read_index = self.read_index.load(Ordering::Relaxed);
-        let write_index = self.write_index.load(Ordering::Relaxed);
+        let write_index = self.cursors.load_write(Ordering::Relaxed);
+        let first_run = to_write.min(capacity - write_index);
+        let second_run = to_write - first_run;
 
-        let item = unsafe { *self.buffer.0.get().as_ref().unwrap().get_unchecked(read_index) };
+        unsafe {
+            let buffer = self.buffer.0.get().as_mut().unwrap();
+            let dst = buffer.as_mut_ptr() as *mut T;
 
-        let next_read_index = (read_index + 1) % self.size;
+            ptr::copy_nonoverlapping(src.as_ptr(), dst.add(write_index), first_run);
 
-        if next_read_index == write_index {
-            self.limit_kind.store(LimitKind::Empty, Ordering::Relaxed)
+            if second_run > 0 {
+                ptr::copy_nonoverlapping(src.as_ptr().add(first_run), dst, second_run);
+            }
         }
 
-        self.read_index.store(next_read_index, Ordering::Release);
+        self.cursors.advance_write(write_index, to_write, capacity);
 
-        Ok(item)
+        to_write
     }
 
-    pub fn push(&self, item: T) -> Result<(), StoreErrorKind> {
-        if self.is_full() {
-            return Err(StoreErrorKind::Full);
+    /// Pops as many elements into `dst` as are available, returning the
+    /// count copied. See [`Self::push_slice`] for the wraparound strategy.
+    fn pop_slice(&self, dst: &mut [T]) -> usize {
+        let to_read = self.peek_slice(dst);
+
+        if to_read == 0 {
+            return 0;
         }
 
-        let write_index = self.write_index.load(Ordering::Relaxed);
-        let read_index = self.read_index.load(Ordering::Relaxed);
+        let capacity = self.size + 1;
+        let read_index = self.cursors.load_read(Ordering::Relaxed);
+
+        self.cursors.advance_read(read_index, to_read, capacity);
+
+        to_read
+    }
+
+    /// Copies as many elements into `dst` as are available without
+    /// consuming them, returning the count copied.
+    fn peek_slice(&self, dst: &mut [T]) -> usize {
+        let capacity = self.size + 1;
+        let to_read = dst.len().min(self.read_available());
+
+        if to_read == 0 {
+            return 0;
+        }
+
+        let read_index = self.cursors.load_read(Ordering::Relaxed);
+        let first_run = to_read.min(capacity - read_index);
+        let second_run = to_read - first_run;
 
         unsafe {
-            *self.buffer.0.get().as_mut().unwrap().get_unchecked_mut(write_index) = item;
+            let buffer = self.buffer.0.get().as_ref().unwrap();
+            let src = buffer.as_ptr() as *const T;
+
+            ptr::copy_nonoverlapping(src.add(read_index), dst.as_mut_ptr(), first_run);
+
+            if second_run > 0 {
+                ptr::copy_nonoverlapping(src, dst.as_mut_ptr().add(first_run), second_run);
+            }
         }
 
-        let next_write_index = (write_index + 1) % self.size;
+        to_read
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Drop for Inner<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+#[cfg(feature = "alloc")]
+unsafe impl<T: Send> Sync for Inner<T> {
+}
+
+#[cfg(feature = "alloc")]
+unsafe impl<T: Send> Send for Inner<T> {
+}
+
+/// Single-owner SPSC ring buffer.
+///
+/// `SpscRingbuffer` itself is `Send` but not `Sync`: there is no blanket
+/// `Sync` impl and no way to reach the backing storage through a shared
+/// reference from two threads, so `Arc::new(buffer).clone()`-ing it and
+/// calling `push`/`pop` from two threads at once is a compile error, not
+/// just a documented hazard. To actually share it across threads, call
+/// [`Self::split`], which moves the shared storage into the private
+/// [`Inner`] type and hands out exactly one [`Producer`] and one
+/// [`Consumer`] — the only two handles that can ever reach it concurrently.
+#[cfg(feature = "alloc")]
+pub struct SpscRingbuffer<T> {
+    inner: Inner<T>,
+    _not_sync: PhantomData<*const ()>,
+}
 
-        if next_write_index == read_index {
-            self.limit_kind.store(LimitKind::Full, Ordering::Relaxed)
+#[cfg(feature = "alloc")]
+impl<T> fmt::Debug for SpscRingbuffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SpscRingbuffer")
+            .field("capacity", &self.inner.size)
+            .field("read_available", &self.inner.read_available())
+            .finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> SpscRingbuffer<T> {
+    pub fn new(size: usize) -> SpscRingbuffer<T> {
+        SpscRingbuffer {
+            inner: Inner::new(size),
+            _not_sync: PhantomData,
         }
+    }
 
-        self.write_index.store(next_write_index, Ordering::Release);
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
 
-        Ok(())
+    pub fn is_full(&self) -> bool {
+        self.inner.is_full()
+    }
+
+    pub fn clear(&self) {
+        self.inner.clear()
+    }
+
+    pub fn read_available(&self) -> usize {
+        self.inner.read_available()
+    }
+
+    pub fn write_available(&self) -> usize {
+        self.inner.write_available()
+    }
+
+    pub fn pop(&self) -> Result<T, LoadErrorKind> {
+        self.inner.pop()
+    }
+
+    pub fn push(&self, item: T) -> Result<(), StoreErrorKind> {
+        self.inner.push(item)
+    }
+
+    /// Splits the buffer into a [`Producer`] and a [`Consumer`] handle.
+    ///
+    /// Unlike `SpscRingbuffer` itself, whose `push` and `pop` can only be
+    /// driven from the one thread that owns it, the returned handles can be
+    /// moved to different threads. Each is `Send` but not `Sync`: the type
+    /// system enforces that at most one thread pushes and at most one thread pops,
+    /// which is the invariant the unsafe indexing in `push`/`pop` relies on.
+    pub fn split(self) -> (Producer<T>, Consumer<T>) {
+        let inner = Arc::new(self.inner);
+
+        (
+            Producer {
+                inner: inner.clone(),
+                _not_sync: PhantomData,
+            },
+            Consumer {
+                inner,
+                _not_sync: PhantomData,
+            },
+        )
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Copy> SpscRingbuffer<T> {
+    pub fn push_slice(&self, src: &[T]) -> usize {
+        self.inner.push_slice(src)
+    }
+
+    pub fn pop_slice(&self, dst: &mut [T]) -> usize {
+        self.inner.pop_slice(dst)
+    }
+
+    pub fn peek_slice(&self, dst: &mut [T]) -> usize {
+        self.inner.peek_slice(dst)
+    }
+}
+
+#[cfg(feature = "alloc")]
+unsafe impl<T: Send> Send for SpscRingbuffer<T> {
+}
+
+/// The producing half of a [`SpscRingbuffer`], obtained via
+/// [`SpscRingbuffer::split`].
+#[cfg(feature = "alloc")]
+pub struct Producer<T> {
+    inner: Arc<Inner<T>>,
+    _not_sync: PhantomData<*const ()>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> fmt::Debug for Producer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Producer")
+            .field("write_available", &self.write_available())
+            .finish()
     }
 }
 
-unsafe impl<T: Copy + Default> Sync for SpscRingbuffer<T> {
+#[cfg(feature = "alloc")]
+impl<T> Producer<T> {
+    pub fn push(&self, item: T) -> Result<(), StoreErrorKind> {
+        self.inner.push(item)
+    }
+
+    pub fn write_available(&self) -> usize {
+        self.inner.write_available()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.inner.is_full()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Copy> Producer<T> {
+    pub fn push_slice(&self, src: &[T]) -> usize {
+        self.inner.push_slice(src)
+    }
 }
 
-unsafe impl<T: Copy + Default> Send for SpscRingbuffer<T> {
+#[cfg(feature = "alloc")]
+unsafe impl<T: Send> Send for Producer<T> {
 }
 
-#[cfg(test)]
+/// The consuming half of a [`SpscRingbuffer`], obtained via
+/// [`SpscRingbuffer::split`].
+#[cfg(feature = "alloc")]
+pub struct Consumer<T> {
+    inner: Arc<Inner<T>>,
+    _not_sync: PhantomData<*const ()>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> fmt::Debug for Consumer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Consumer")
+            .field("read_available", &self.read_available())
+            .finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Consumer<T> {
+    pub fn pop(&self) -> Result<T, LoadErrorKind> {
+        self.inner.pop()
+    }
+
+    pub fn read_available(&self) -> usize {
+        self.inner.read_available()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Copy> Consumer<T> {
+    pub fn pop_slice(&self, dst: &mut [T]) -> usize {
+        self.inner.pop_slice(dst)
+    }
+
+    pub fn peek_slice(&self, dst: &mut [T]) -> usize {
+        self.inner.peek_slice(dst)
+    }
+}
+
+#[cfg(feature = "alloc")]
+unsafe impl<T: Send> Send for Consumer<T> {
+}
+
+#[cfg(all(test, feature = "alloc"))]
 mod tests_api {
     use super::*;
 
@@ -349,49 +698,488 @@ mod tests_api {
 
         assert!(buffer.is_empty());
     }
+
+    #[test]
+    fn split() {
+        let buffer = SpscRingbuffer::<u32>::new(32);
+        let (producer, consumer) = buffer.split();
+
+        producer.push(1).unwrap();
+        assert_eq!(consumer.pop().unwrap(), 1);
+    }
+
+    #[test]
+    fn split_across_threads() {
+        let buffer = SpscRingbuffer::<u32>::new(32);
+        let (producer, consumer) = buffer.split();
+
+        let writer = std::thread::spawn(move || {
+            for i in 0..32 {
+                producer.push(i).unwrap();
+            }
+        });
+
+        writer.join().unwrap();
+
+        for i in 0..32 {
+            assert_eq!(consumer.pop().unwrap(), i);
+        }
+    }
+
+    #[test]
+    fn wraps_around_without_losing_capacity() {
+        let buffer = SpscRingbuffer::<u32>::new(8);
+
+        for _ in 0..3 {
+            for i in 0..8 {
+                buffer.push(i).unwrap();
+            }
+
+            assert!(buffer.is_full());
+            assert_eq!(buffer.push(0), Err(StoreErrorKind::Full));
+
+            for i in 0..8 {
+                assert_eq!(buffer.pop().unwrap(), i);
+            }
+
+            assert!(buffer.is_empty());
+        }
+    }
+
+    #[test]
+    fn push_slice() {
+        let buffer = SpscRingbuffer::<u32>::new(8);
+
+        assert_eq!(buffer.push_slice(&[1, 2, 3, 4, 5]), 5);
+        assert_eq!(buffer.read_available(), 5);
+        assert_eq!(buffer.push_slice(&[6, 7, 8, 9]), 3);
+        assert!(buffer.is_full());
+    }
+
+    #[test]
+    fn pop_slice() {
+        let buffer = SpscRingbuffer::<u32>::new(8);
+
+        buffer.push_slice(&[1, 2, 3, 4, 5]);
+
+        let mut dst = [0; 8];
+        assert_eq!(buffer.pop_slice(&mut dst), 5);
+        assert_eq!(&dst[..5], &[1, 2, 3, 4, 5]);
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn peek_slice() {
+        let buffer = SpscRingbuffer::<u32>::new(8);
+
+        buffer.push_slice(&[1, 2, 3]);
+
+        let mut dst = [0; 3];
+        assert_eq!(buffer.peek_slice(&mut dst), 3);
+        assert_eq!(dst, [1, 2, 3]);
+        assert_eq!(buffer.read_available(), 3);
+    }
+
+    #[test]
+    fn slice_wraps_around() {
+        let buffer = SpscRingbuffer::<u32>::new(8);
+
+        buffer.push_slice(&[1, 2, 3, 4, 5, 6]);
+
+        let mut dst = [0; 4];
+        buffer.pop_slice(&mut dst);
+
+        assert_eq!(buffer.push_slice(&[7, 8, 9, 10]), 4);
+
+        let mut dst = [0; 6];
+        assert_eq!(buffer.pop_slice(&mut dst), 6);
+        assert_eq!(dst, [5, 6, 7, 8, 9, 10]);
+    }
+
+    #[test]
+    fn non_copy_values() {
+        let buffer = SpscRingbuffer::<String>::new(4);
+
+        buffer.push(String::from("a")).unwrap();
+        buffer.push(String::from("b")).unwrap();
+
+        assert_eq!(buffer.pop().unwrap(), "a");
+        assert_eq!(buffer.pop().unwrap(), "b");
+        assert_eq!(buffer.pop(), Err(LoadErrorKind::Empty));
+    }
+
+    #[test]
+    fn drop_runs_destructors_for_occupied_slots_only() {
+        use std::rc::Rc;
+
+        let shared = Rc::new(());
+        let buffer = SpscRingbuffer::<Rc<()>>::new(4);
+
+        buffer.push(shared.clone()).unwrap();
+        buffer.push(shared.clone()).unwrap();
+        buffer.pop().unwrap();
+
+        assert_eq!(Rc::strong_count(&shared), 2);
+
+        drop(buffer);
+
+        assert_eq!(Rc::strong_count(&shared), 1);
+    }
+}
+
+/// `no_std`-friendly fixed-capacity ring buffer backed by an inline array
+/// instead of a heap-allocated `Vec`, mirroring the const-generic migration
+/// `heapless` made for its own SPSC queue.
+///
+/// Unlike [`SpscRingbuffer`], which sizes its backing storage at runtime via
+/// [`SpscRingbuffer::new`], [`fixed::SpscRingbuffer`] is sized at compile
+/// time via the `N` const generic and constructed with a `const fn`, so it
+/// can live in a `static` on targets with no allocator. Being `'static` is
+/// also what lets [`fixed::SpscRingbuffer::split`] hand out `Send` handles
+/// without an `Arc`: `push`/`pop` are private, so a [`fixed::Producer`] and
+/// [`fixed::Consumer`] obtained from `split` are the only way to reach them
+/// at all, the same guarantee [`SpscRingbuffer::split`] gives the
+/// heap-allocated type.
+#[cfg(feature = "const-generic")]
+pub mod fixed {
+    use core::{
+        cell::UnsafeCell,
+        fmt,
+        marker::PhantomData,
+        mem::MaybeUninit,
+        ptr,
+        sync::atomic::{
+            AtomicBool,
+            Ordering,
+        },
+    };
+
+    use super::{
+        Cursors,
+        LoadErrorKind,
+        StoreErrorKind,
+    };
+
+    struct UnsafeArray<T, const N: usize>(UnsafeCell<[MaybeUninit<T>; N]>);
+
+    /// Fixed-capacity SPSC ring buffer with an inline `[MaybeUninit<T>; N]`
+    /// backing array.
+    ///
+    /// One of the `N` allocated slots is reserved to distinguish full from
+    /// empty (the same trick [`super::SpscRingbuffer`] uses), so the usable
+    /// capacity is `N - 1`. The index arithmetic itself lives in
+    /// [`super::Cursors`], shared with the heap-allocated type.
+    ///
+    /// `push`/`pop` are private: the only way to reach them is through the
+    /// [`Producer`]/[`Consumer`] pair returned by [`Self::split`], which can
+    /// only be obtained once per buffer. Without that, this being `Sync` (a
+    /// requirement for living in a `static` at all) would let two threads
+    /// holding nothing more than a shared `&SpscRingbuffer` call `push`
+    /// concurrently and race on the same slot.
+    pub struct SpscRingbuffer<T, const N: usize> {
+        buffer: UnsafeArray<T, N>,
+        cursors: Cursors,
+        taken: AtomicBool,
+    }
+
+    impl<T, const N: usize> SpscRingbuffer<T, N> {
+        pub const fn new() -> SpscRingbuffer<T, N> {
+            assert!(N > 1, "SpscRingbuffer needs at least 2 slots to reserve one for full/empty disambiguation");
+
+            SpscRingbuffer {
+                // Safety: an array of `MaybeUninit<T>` needs no initialization.
+                buffer: UnsafeArray(UnsafeCell::new(unsafe { MaybeUninit::uninit().assume_init() })),
+                cursors: Cursors::new(),
+                taken: AtomicBool::new(false),
+            }
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.read_available() == 0
+        }
+
+        pub fn is_full(&self) -> bool {
+            self.write_available() == 0
+        }
+
+        fn clear(&self) {
+            while self.pop().is_ok() {
+            }
+        }
+
+        pub fn read_available(&self) -> usize {
+            self.cursors.read_available(N)
+        }
+
+        pub fn write_available(&self) -> usize {
+            self.cursors.write_available(N)
+        }
+
+        fn pop(&self) -> Result<T, LoadErrorKind> {
+            if self.is_empty() {
+                return Err(LoadErrorKind::Empty);
+            }
+
+            let read_index = self.cursors.load_read(Ordering::Relaxed);
+
+            let item = unsafe {
+                let slot = (*self.buffer.0.get()).get_unchecked(read_index);
+                ptr::read(slot.as_ptr())
+            };
+
+            self.cursors.advance_read(read_index, 1, N);
+
+            Ok(item)
+        }
+
+        fn push(&self, item: T) -> Result<(), StoreErrorKind> {
+            if self.is_full() {
+                return Err(StoreErrorKind::Full);
+            }
+
+            let write_index = self.cursors.load_write(Ordering::Relaxed);
+
+            unsafe {
+                let slot = (*self.buffer.0.get()).get_unchecked_mut(write_index);
+                ptr::write(slot.as_mut_ptr(), item);
+            }
+
+            self.cursors.advance_write(write_index, 1, N);
+
+            Ok(())
+        }
+
+        /// Splits the buffer into a [`Producer`] and a [`Consumer`] handle.
+        ///
+        /// Requires `&'static self` (e.g. a `static` buffer), since the
+        /// returned handles are `Send` and need to outlive whatever thread
+        /// they're moved into. Only the first call succeeds: `push`/`pop`
+        /// are private, so these handles are the only way to reach them at
+        /// all, which is what keeps at most one producer and one consumer
+        /// able to reach the buffer concurrently.
+        ///
+        /// # Panics
+        ///
+        /// Panics if called more than once on the same buffer.
+        pub fn split(&'static self) -> (Producer<'static, T, N>, Consumer<'static, T, N>) {
+            self.taken
+                .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+                .expect("SpscRingbuffer::split must only be called once");
+
+            (
+                Producer {
+                    inner: self,
+                    _not_sync: PhantomData,
+                },
+                Consumer {
+                    inner: self,
+                    _not_sync: PhantomData,
+                },
+            )
+        }
+    }
+
+    impl<T, const N: usize> Drop for SpscRingbuffer<T, N> {
+        fn drop(&mut self) {
+            self.clear();
+        }
+    }
+
+    impl<T, const N: usize> Default for SpscRingbuffer<T, N> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl<T, const N: usize> fmt::Debug for SpscRingbuffer<T, N> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("SpscRingbuffer")
+                .field("capacity", &(N - 1))
+                .field("read_available", &self.read_available())
+                .finish()
+        }
+    }
+
+    unsafe impl<T: Send, const N: usize> Sync for SpscRingbuffer<T, N> {
+    }
+
+    unsafe impl<T: Send, const N: usize> Send for SpscRingbuffer<T, N> {
+    }
+
+    /// The producing half of a [`SpscRingbuffer`], obtained via
+    /// [`SpscRingbuffer::split`].
+    pub struct Producer<'a, T, const N: usize> {
+        inner: &'a SpscRingbuffer<T, N>,
+        _not_sync: PhantomData<*const ()>,
+    }
+
+    impl<'a, T, const N: usize> Producer<'a, T, N> {
+        pub fn push(&self, item: T) -> Result<(), StoreErrorKind> {
+            self.inner.push(item)
+        }
+
+        pub fn write_available(&self) -> usize {
+            self.inner.write_available()
+        }
+
+        pub fn is_full(&self) -> bool {
+            self.inner.is_full()
+        }
+    }
+
+    impl<'a, T, const N: usize> fmt::Debug for Producer<'a, T, N> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("Producer")
+                .field("write_available", &self.write_available())
+                .finish()
+        }
+    }
+
+    unsafe impl<'a, T: Send, const N: usize> Send for Producer<'a, T, N> {
+    }
+
+    /// The consuming half of a [`SpscRingbuffer`], obtained via
+    /// [`SpscRingbuffer::split`].
+    pub struct Consumer<'a, T, const N: usize> {
+        inner: &'a SpscRingbuffer<T, N>,
+        _not_sync: PhantomData<*const ()>,
+    }
+
+    impl<'a, T, const N: usize> Consumer<'a, T, N> {
+        pub fn pop(&self) -> Result<T, LoadErrorKind> {
+            self.inner.pop()
+        }
+
+        pub fn read_available(&self) -> usize {
+            self.inner.read_available()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.inner.is_empty()
+        }
+    }
+
+    impl<'a, T, const N: usize> fmt::Debug for Consumer<'a, T, N> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("Consumer")
+                .field("read_available", &self.read_available())
+                .finish()
+        }
+    }
+
+    unsafe impl<'a, T: Send, const N: usize> Send for Consumer<'a, T, N> {
+    }
+
+    #[cfg(test)]
+    mod tests_fixed {
+        use super::*;
+
+        #[test]
+        fn new() {
+            let _buffer = SpscRingbuffer::<u32, 32>::new();
+        }
+
+        #[test]
+        fn push_pop() {
+            static BUFFER: SpscRingbuffer<u32, 32> = SpscRingbuffer::new();
+            let (producer, consumer) = BUFFER.split();
+
+            producer.push(1).unwrap();
+            assert_eq!(consumer.pop().unwrap(), 1);
+        }
+
+        #[test]
+        fn usable_capacity_is_n_minus_one() {
+            static BUFFER: SpscRingbuffer<u32, 8> = SpscRingbuffer::new();
+            let (producer, _consumer) = BUFFER.split();
+
+            for i in 0..7 {
+                producer.push(i).unwrap();
+            }
+
+            assert!(producer.is_full());
+            assert_eq!(producer.push(7), Err(StoreErrorKind::Full));
+        }
+
+        #[test]
+        fn wraps_around() {
+            static BUFFER: SpscRingbuffer<u32, 8> = SpscRingbuffer::new();
+            let (producer, consumer) = BUFFER.split();
+
+            for _ in 0..3 {
+                for i in 0..7 {
+                    producer.push(i).unwrap();
+                }
+
+                for i in 0..7 {
+                    assert_eq!(consumer.pop().unwrap(), i);
+                }
+
+                assert!(consumer.is_empty());
+            }
+        }
+
+        #[test]
+        fn can_be_a_static() {
+            static BUFFER: SpscRingbuffer<u32, 4> = SpscRingbuffer::new();
+            let (producer, consumer) = BUFFER.split();
+
+            producer.push(1).unwrap();
+            assert_eq!(consumer.pop().unwrap(), 1);
+        }
+
+        #[test]
+        #[should_panic]
+        fn split_twice_panics() {
+            static BUFFER: SpscRingbuffer<u32, 4> = SpscRingbuffer::new();
+
+            let _first = BUFFER.split();
+            let _second = BUFFER.split();
+        }
+    }
 }
 
+/// (De)serializes a [`SpscRingbuffer`] as its logical contents (capacity plus
+/// the currently occupied items) rather than the raw backing storage, which
+/// may hold uninitialized slots past the write cursor.
 #[cfg(feature = "serialization")]
 pub mod serialization {
     use super::*;
     use serde::{
+        Deserialize,
         Deserializer,
+        Serialize,
         Serializer,
     };
 
-    impl<T> Serialize for UnsafeVec<T>
-    where T: Default + Copy + Serialize
-    {
-        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-        where S: Serializer {
-            let buffer = unsafe { &*self.0.get() };
-            <Vec<T> as Serialize>::serialize(buffer, serializer)
-        }
+    #[derive(Serialize, Deserialize)]
+    struct Snapshot<T> {
+        size: usize,
+        items: Vec<T>,
     }
 
-    impl<'de, T> Deserialize<'de> for UnsafeVec<T>
-    where T: Default + Copy + Deserialize<'de>
+    impl<T> Serialize for SpscRingbuffer<T>
+    where T: Copy + Default + Serialize
     {
-        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-        where D: Deserializer<'de> {
-            let buffer = <Vec<T> as Deserialize>::deserialize(deserializer)?;
-            Ok(UnsafeVec(UnsafeCell::new(buffer)))
-        }
-    }
-
-    impl Serialize for AtomicLimitKind {
         fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where S: Serializer {
-            let kind = self.load(Ordering::Relaxed);
-            <LimitKind as Serialize>::serialize(&kind, serializer)
+            let mut items = vec![T::default(); self.read_available()];
+            self.peek_slice(&mut items);
+
+            Snapshot { size: self.inner.size, items }.serialize(serializer)
         }
     }
 
-    impl<'de> Deserialize<'de> for AtomicLimitKind {
+    impl<'de, T> Deserialize<'de> for SpscRingbuffer<T>
+    where T: Copy + Default + Deserialize<'de>
+    {
         fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where D: Deserializer<'de> {
-            let kind = <LimitKind as Deserialize>::deserialize(deserializer)?;
-            Ok(AtomicLimitKind::new(kind))
+            let snapshot = Snapshot::<T>::deserialize(deserializer)?;
+            let buffer = SpscRingbuffer::new(snapshot.size);
+            buffer.push_slice(&snapshot.items);
+
+            Ok(buffer)
         }
     }
 }
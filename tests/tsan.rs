@@ -0,0 +1,163 @@
+//! Concurrency stress tests for the `Acquire`/`Release` pairing between
+//! `push` and `pop`.
+//!
+//! These spawn a real producer thread and a real consumer thread hammering
+//! the buffer across its wraparound boundary, which is the only way to
+//! actually exercise the cross-thread orderings (a single-threaded test can't
+//! surface a missing `Acquire`/`Release`). Both the heap-allocated
+//! `SpscRingbuffer` and the const-generic `fixed::SpscRingbuffer` are
+//! covered, since each has its own `split()`/`Sync`-gating to get right. Run
+//! under ThreadSanitizer to catch any data race a future change might
+//! introduce:
+//!
+//! ```sh
+//! RUSTFLAGS="-Z sanitizer=thread" cargo +nightly test --test tsan --target <host-triple>
+//! ```
+
+use spsc_ringbuffer::fixed;
+use spsc_ringbuffer::SpscRingbuffer;
+use std::thread;
+
+const ITERATIONS: u32 = 1_000_000;
+
+#[test]
+fn fifo_ordering_across_wraparound() {
+    let buffer = SpscRingbuffer::<u32>::new(16);
+    let (producer, consumer) = buffer.split();
+
+    let writer = thread::spawn(move || {
+        for i in 0..ITERATIONS {
+            while producer.push(i).is_err() {
+                thread::yield_now();
+            }
+        }
+    });
+
+    let reader = thread::spawn(move || {
+        for expected in 0..ITERATIONS {
+            let item = loop {
+                match consumer.pop() {
+                    Ok(item) => break item,
+                    Err(_) => thread::yield_now(),
+                }
+            };
+
+            assert_eq!(item, expected, "items must be observed in FIFO order with no loss or duplication");
+        }
+    });
+
+    writer.join().unwrap();
+    reader.join().unwrap();
+}
+
+#[test]
+fn monotonic_counter_has_no_gaps() {
+    let buffer = SpscRingbuffer::<u64>::new(8);
+    let (producer, consumer) = buffer.split();
+
+    let writer = thread::spawn(move || {
+        for i in 0..ITERATIONS as u64 {
+            while producer.push(i).is_err() {
+                thread::yield_now();
+            }
+        }
+    });
+
+    let reader = thread::spawn(move || {
+        let mut last = None;
+
+        for _ in 0..ITERATIONS as u64 {
+            let item = loop {
+                match consumer.pop() {
+                    Ok(item) => break item,
+                    Err(_) => thread::yield_now(),
+                }
+            };
+
+            if let Some(last) = last {
+                assert_eq!(item, last + 1, "consumer must observe a strictly increasing sequence with no gaps");
+            }
+
+            last = Some(item);
+        }
+    });
+
+    writer.join().unwrap();
+    reader.join().unwrap();
+}
+
+#[test]
+fn fixed_fifo_ordering_across_wraparound() {
+    static BUFFER: fixed::SpscRingbuffer<u32, 16> = fixed::SpscRingbuffer::new();
+    let (producer, consumer) = BUFFER.split();
+
+    let writer = thread::spawn(move || {
+        for i in 0..ITERATIONS {
+            while producer.push(i).is_err() {
+                thread::yield_now();
+            }
+        }
+    });
+
+    let reader = thread::spawn(move || {
+        for expected in 0..ITERATIONS {
+            let item = loop {
+                match consumer.pop() {
+                    Ok(item) => break item,
+                    Err(_) => thread::yield_now(),
+                }
+            };
+
+            assert_eq!(item, expected, "items must be observed in FIFO order with no loss or duplication");
+        }
+    });
+
+    writer.join().unwrap();
+    reader.join().unwrap();
+}
+
+#[test]
+fn bulk_slice_transfer_preserves_order() {
+    let buffer = SpscRingbuffer::<u32>::new(64);
+    let (producer, consumer) = buffer.split();
+
+    let writer = thread::spawn(move || {
+        let mut next = 0u32;
+
+        while next < ITERATIONS {
+            let chunk: Vec<u32> = (next..(next + 32).min(ITERATIONS)).collect();
+            let mut sent = 0;
+
+            while sent < chunk.len() {
+                sent += producer.push_slice(&chunk[sent..]);
+
+                if sent < chunk.len() {
+                    thread::yield_now();
+                }
+            }
+
+            next += chunk.len() as u32;
+        }
+    });
+
+    let reader = thread::spawn(move || {
+        let mut expected = 0u32;
+        let mut dst = [0u32; 32];
+
+        while expected < ITERATIONS {
+            let read = consumer.pop_slice(&mut dst);
+
+            for &item in &dst[..read] {
+                assert_eq!(item, expected, "bulk transfers must preserve element order");
+                expected += 1;
+            }
+
+            if read == 0 {
+                thread::yield_now();
+            }
+        }
+    });
+
+    writer.join().unwrap();
+    reader.join().unwrap();
+}